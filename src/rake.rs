@@ -3,46 +3,335 @@ use keyword::{KeywordScore, KeywordSort};
 use regex::Regex;
 use std::collections::HashMap;
 use stopwords::StopWords;
+use tokenizer::{Tokenizer, WhitespaceTokenizer};
+use trie::Trie;
 
 /// Represents an instance of Rake type
+///
+/// `T` is the word-level tokenizer used inside `phrases`; it defaults to
+/// whitespace splitting and can be swapped via `Rake::with_tokenizer`.
 #[derive(Debug, Clone)]
-pub struct Rake {
+pub struct Rake<T = WhitespaceTokenizer> {
     stop_words: StopWords,
     num_re: Regex,
     punc_re: Regex,
+    tokenizer: T,
+    min_adjoin: usize,
+    merge_threshold: Option<usize>,
+    min_words: usize,
+    max_words: Option<usize>,
+    min_keyword_len: usize,
+    min_word_count: usize,
+    stem_stopwords: bool,
 }
 
-impl Rake {
+/// An element of a sentence's token stream: a candidate phrase or a separating
+/// stopword. `run` keeps the stopwords for adjoining detection; `phrases` drops
+/// them.
+enum Token<'a> {
+    Phrase(Vec<&'a str>),
+    Stop(&'a str),
+}
+
+impl Rake<WhitespaceTokenizer> {
     /// Create a new instance of `Rake`.
     /// `stop_words` is an instance of `StopWords` struct.
     pub fn new(stop_words: StopWords) -> Self {
+        Rake::with_tokenizer(stop_words, WhitespaceTokenizer)
+    }
+}
+
+impl<T: Tokenizer> Rake<T> {
+    /// Create a new instance of `Rake` that segments words with `tokenizer`.
+    /// The punctuation-based sentence splitting in `phrases` is unchanged; only
+    /// the word step uses `tokenizer`.
+    pub fn with_tokenizer(stop_words: StopWords, tokenizer: T) -> Self {
         Rake {
             stop_words: stop_words,
             num_re: Regex::new(r"-?\p{N}+[./٫,']?\p{N}*").expect("bad regex"),
             punc_re: Regex::new(r"[^\P{P}-]|\s+-\s+").expect("bad regex"),
+            tokenizer: tokenizer,
+            min_adjoin: 2,
+            merge_threshold: None,
+            min_words: 1,
+            max_words: None,
+            min_keyword_len: 0,
+            min_word_count: 1,
+            stem_stopwords: false,
         }
     }
 
+    /// Sets how many times an adjoining sequence must recur before `run`
+    /// promotes it to a keyword (see [`run`](Self::run)). `0` disables
+    /// adjoining-keyword detection entirely. The default is `2`.
+    pub fn with_min_adjoin(mut self, min_adjoin: usize) -> Self {
+        self.min_adjoin = min_adjoin;
+        self
+    }
+
+    /// Enables merging of near-duplicate keywords in `run` at edit distance
+    /// `threshold`. Off by default.
+    pub fn with_merge_threshold(mut self, threshold: usize) -> Self {
+        self.merge_threshold = Some(threshold);
+        self
+    }
+
     /// Runs RAKE algorithm on `text` and returns a vector of keywords.
     /// The returned vector is sorted by score (from greater to less).
+    ///
+    /// With the default `min_adjoin = 2`, keyword sequences that recur in the
+    /// same order separated only by stopwords are also recovered as single
+    /// adjoining keywords. This means `Rake::new(sw).run(text)` can emit such
+    /// entries where older versions did not; set `min_adjoin` to `0` to restore
+    /// the plain degree/frequency output.
     pub fn run(&self, text: &str) -> Vec<KeywordScore> {
-        let phrases = self.phrases(text);
+        let stream = self.token_stream(text);
+        let phrases: Vec<Vec<&str>> = Self::stream_phrases(&stream)
+            .into_iter()
+            .filter(|phrase| self.within_window(phrase))
+            .collect();
         let word_scores = self.word_scores(&phrases);
-        self.candidate_keywords(&phrases, word_scores)
+        let mut keyword_score = self.candidate_keyword_scores(&phrases, &word_scores);
+        if self.min_adjoin > 0 {
+            for (keyword, score) in self.adjoining_keywords(&stream, &word_scores) {
+                keyword_score.entry(keyword).or_insert(score);
+            }
+        }
+        let mut keywords = KeywordScore::from_map(keyword_score);
+        keywords.sort_by_score();
+        if let Some(threshold) = self.merge_threshold {
+            keywords = Self::merge_near_duplicates(keywords, threshold);
+        }
+        keywords
     }
 
-    fn candidate_keywords<'a>(
+    /// Collapses near-duplicate keywords: clusters lowercased forms that are
+    /// within a length-scaled edit distance (via a trie) or share a long common
+    /// prefix, keeping the highest-scoring surface form and summing the scores.
+    fn merge_near_duplicates(keywords: Vec<KeywordScore>, threshold: usize) -> Vec<KeywordScore> {
+        let forms: Vec<String> = keywords.iter().map(|k| k.keyword.to_lowercase()).collect();
+        let mut trie = Trie::new();
+        for form in &forms {
+            trie.insert(form);
+        }
+        let mut index: HashMap<&str, Vec<usize>> = HashMap::new();
+        for (i, form) in forms.iter().enumerate() {
+            index.entry(form.as_str()).or_default().push(i);
+        }
+
+        let mut parent: Vec<usize> = (0..keywords.len()).collect();
+        for (i, form) in forms.iter().enumerate() {
+            for neighbour in trie.fuzzy(form, edit_budget(form, threshold)) {
+                if let Some(js) = index.get(neighbour.as_str()) {
+                    for &j in js {
+                        union(&mut parent, i, j);
+                    }
+                }
+            }
+            for (j, other) in forms.iter().enumerate().skip(i + 1) {
+                if shares_prefix(form, other, threshold) {
+                    union(&mut parent, i, j);
+                }
+            }
+        }
+
+        // Accumulate each cluster's total score against its best surface form.
+        // `keywords` arrives sorted from greater to less, so the first member
+        // seen for a cluster is its highest-scoring surface form.
+        let mut clusters: HashMap<usize, (String, f64)> = HashMap::new();
+        for (i, keyword) in keywords.into_iter().enumerate() {
+            let root = find(&mut parent, i);
+            let entry = clusters
+                .entry(root)
+                .or_insert_with(|| (keyword.keyword.clone(), 0f64));
+            entry.1 += keyword.score;
+        }
+        let merged = clusters.into_iter().map(|(_, v)| v).collect();
+        let mut keywords = KeywordScore::from_map(merged);
+        keywords.sort_by_score();
+        keywords
+    }
+
+    /// Runs RAKE over a corpus, multiplying each word's RAKE score by
+    /// `idf = ln((N + 1) / (df + 1)) + 1` (with `df` its document frequency out
+    /// of `N = docs.len()`) so words common across the collection are demoted.
+    /// Returns one sorted score vector per input document.
+    pub fn run_corpus(&self, docs: &[&str]) -> Vec<Vec<KeywordScore>> {
+        let n = docs.len();
+        let phrases: Vec<Vec<Vec<&str>>> = docs.iter().map(|doc| self.phrases(doc)).collect();
+
+        let mut df: HashMap<&str, usize> = HashMap::new();
+        for doc_phrases in &phrases {
+            let mut seen = std::collections::HashSet::new();
+            doc_phrases
+                .iter()
+                .flat_map(|phrase| phrase.iter())
+                .filter(|word| !self.is_number(word))
+                .for_each(|word| {
+                    if seen.insert(*word) {
+                        *df.entry(*word).or_insert(0) += 1;
+                    }
+                });
+        }
+        let idf: HashMap<&str, f64> = df
+            .into_iter()
+            .map(|(word, df)| (word, ((n as f64 + 1.0) / (df as f64 + 1.0)).ln() + 1.0))
+            .collect();
+
+        phrases
+            .iter()
+            .map(|doc_phrases| {
+                let word_scores = self.word_scores(doc_phrases);
+                self.candidate_keywords_idf(doc_phrases, word_scores, &idf)
+            })
+            .collect()
+    }
+
+    fn candidate_keyword_scores<'a>(
+        &self,
+        phrases: &[Vec<&'a str>],
+        word_scores: &HashMap<&'a str, f64>,
+    ) -> HashMap<String, f64> {
+        let word_freq = self.word_frequencies(phrases);
+        let mut keyword_score = HashMap::with_capacity(phrases.len());
+        phrases.iter().for_each(|phrase| {
+            if !self.passes_filters(phrase, &word_freq) {
+                return;
+            }
+            let mut candidate_score = 0f64;
+            phrase
+                .iter()
+                .filter(|word| !self.is_number(word))
+                .for_each(|word| candidate_score += word_scores[word]);
+            *keyword_score.entry(phrase.join(" ")).or_insert(0f64) = candidate_score;
+        });
+        keyword_score
+    }
+
+    /// Recovers keywords made of several extracted keywords joined by interior
+    /// stopwords. Runs of two or more consecutive phrases in a sentence are
+    /// separated only by stopwords, so each such run recurring at least
+    /// `min_adjoin` times becomes a candidate. The score sums every spanned
+    /// non-number word's RAKE score; interior stopwords are scored by
+    /// `stopword_scores` over every adjacency in the text.
+    fn adjoining_keywords<'a>(
+        &self,
+        stream: &[Vec<Token<'a>>],
+        word_scores: &HashMap<&'a str, f64>,
+    ) -> HashMap<String, f64> {
+        // Collect every consecutive-phrase run (length >= 2) as its full word
+        // list, interior stopwords included, keyed by surface form. `contexts`
+        // holds every adjacent phrase pair (each occurrence) so interior
+        // stopwords can be scored over the whole document, not just kept runs.
+        let mut runs: HashMap<String, (Vec<&'a str>, usize)> = HashMap::new();
+        let mut contexts: Vec<Vec<&'a str>> = Vec::new();
+        for tokens in stream {
+            // Per phrase, the content words and the stopwords that separate it
+            // from the next phrase (trailing stops after the last phrase, and
+            // leading stops before the first, are not interior to any run).
+            let mut items: Vec<(&Vec<&'a str>, Vec<&'a str>)> = Vec::new();
+            let mut pending: Vec<&'a str> = Vec::new();
+            for token in tokens {
+                match token {
+                    Token::Stop(stop) => pending.push(*stop),
+                    Token::Phrase(phrase) => {
+                        if let Some(last) = items.last_mut() {
+                            last.1 = std::mem::take(&mut pending);
+                        } else {
+                            pending.clear();
+                        }
+                        items.push((phrase, Vec::new()));
+                    }
+                }
+            }
+            for i in 0..items.len() {
+                let mut words = items[i].0.to_vec();
+                for j in (i + 1)..items.len() {
+                    words.extend(items[j - 1].1.iter().copied());
+                    words.extend(items[j].0.iter().copied());
+                    let entry = runs
+                        .entry(words.join(" "))
+                        .or_insert_with(|| (words.clone(), 0));
+                    entry.1 += 1;
+                }
+            }
+            for pair in items.windows(2) {
+                let mut ctx = pair[0].0.to_vec();
+                ctx.extend(pair[0].1.iter().copied());
+                ctx.extend(pair[1].0.iter().copied());
+                contexts.push(ctx);
+            }
+        }
+
+        let stop_scores = self.stopword_scores(&contexts, word_scores);
+
+        runs.iter()
+            .filter(|(_, (_, count))| *count >= self.min_adjoin)
+            .map(|(surface, (words, _))| {
+                let score = words
+                    .iter()
+                    .filter(|word| !self.is_number(word))
+                    .map(|word| {
+                        word_scores
+                            .get(word)
+                            .or_else(|| stop_scores.get(word))
+                            .copied()
+                            .unwrap_or(0f64)
+                    })
+                    .sum();
+                (surface.clone(), score)
+            })
+            .collect()
+    }
+
+    /// Degree/frequency scores for interior stopwords, computed with the
+    /// `word_scores` formula over every adjacency `contexts` in the document so
+    /// a stopword is scored over the same document-wide population as a content
+    /// word rather than over the surviving runs alone.
+    fn stopword_scores<'a>(
+        &self,
+        contexts: &[Vec<&'a str>],
+        word_scores: &HashMap<&'a str, f64>,
+    ) -> HashMap<&'a str, f64> {
+        let mut word_freq = HashMap::new();
+        let mut word_degree = HashMap::new();
+        for run in contexts {
+            let len: usize = run.iter().filter(|word| !self.is_number(word)).count();
+            if len == 0 {
+                continue;
+            }
+            run.iter()
+                .filter(|word| !self.is_number(word) && !word_scores.contains_key(*word))
+                .for_each(|word| {
+                    *word_freq.entry(*word).or_insert(0) += 1;
+                    *word_degree.entry(*word).or_insert(0) += len - 1;
+                });
+        }
+        word_freq
+            .into_iter()
+            .map(|(word, freq)| (word, (word_degree[word] + freq) as f64 / freq as f64))
+            .collect()
+    }
+
+    fn candidate_keywords_idf<'a>(
         &self,
         phrases: &[Vec<&'a str>],
         word_scores: HashMap<&'a str, f64>,
+        idf: &HashMap<&'a str, f64>,
     ) -> Vec<KeywordScore> {
+        let word_freq = self.word_frequencies(phrases);
         let mut keyword_score = HashMap::with_capacity(phrases.len());
         phrases.iter().for_each(|phrase| {
+            if !self.passes_filters(phrase, &word_freq) {
+                return;
+            }
             let mut candidate_score = 0f64;
             phrase
                 .iter()
                 .filter(|word| !self.is_number(word))
-                .for_each(|word| candidate_score += word_scores[word]);
+                .for_each(|word| {
+                    candidate_score += word_scores[word] * idf.get(word).copied().unwrap_or(1f64)
+                });
             *keyword_score.entry(phrase.join(" ")).or_insert(0f64) = candidate_score;
         });
         let mut keywords = KeywordScore::from_map(keyword_score);
@@ -78,29 +367,349 @@ impl Rake {
     }
 
     fn phrases<'a>(&'a self, text: &'a str) -> Vec<Vec<&'a str>> {
-        let mut phrases = Vec::new();
+        Self::stream_phrases(&self.token_stream(text))
+            .into_iter()
+            .filter(|phrase| self.within_window(phrase))
+            .collect()
+    }
+
+    /// Total occurrence count of every non-number word across `phrases`, used
+    /// by the minimum-word-count filter.
+    fn word_frequencies<'a>(&self, phrases: &[Vec<&'a str>]) -> HashMap<&'a str, usize> {
+        let mut freq = HashMap::new();
+        phrases
+            .iter()
+            .flat_map(|phrase| phrase.iter())
+            .filter(|word| !self.is_number(word))
+            .for_each(|word| *freq.entry(*word).or_insert(0) += 1);
+        freq
+    }
+
+    /// Whether a candidate phrase's word count falls within the configured
+    /// `min_words`/`max_words` window.
+    fn within_window(&self, phrase: &[&str]) -> bool {
+        let words = phrase.len();
+        if words < self.min_words {
+            return false;
+        }
+        match self.max_words {
+            Some(max) => words <= max,
+            None => true,
+        }
+    }
+
+    /// Whether a candidate survives the per-keyword character-length and
+    /// minimum-word-count filters.
+    fn passes_filters(&self, phrase: &[&str], word_freq: &HashMap<&str, usize>) -> bool {
+        if phrase.join(" ").chars().count() < self.min_keyword_len {
+            return false;
+        }
+        !phrase
+            .iter()
+            .filter(|word| !self.is_number(word))
+            .any(|word| word_freq.get(word).copied().unwrap_or(0) < self.min_word_count)
+    }
+
+    /// Whether `word` matches a stopword. With stopword stemming enabled,
+    /// plural and possessive forms are reduced to their base before the lookup
+    /// so "implements"/"implementing" and "crate's" collapse onto the stopword
+    /// set.
+    fn is_stopword(&self, word: &str) -> bool {
+        let lower = word.to_lowercase();
+        if self.stop_words.contains(lower.as_str()) {
+            return true;
+        }
+        if self.stem_stopwords {
+            let stem = stem(&lower);
+            if stem != lower && self.stop_words.contains(stem.as_str()) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Splits `text` into per-sentence token streams, emitting each stopword as
+    /// a `Stop` token and the content-word runs between them as `Phrase` tokens
+    /// so adjoining detection can see the stopword runs `phrases` discards.
+    fn token_stream<'a>(&'a self, text: &'a str) -> Vec<Vec<Token<'a>>> {
+        let mut stream = Vec::new();
         self.punc_re.split(text).filter(|s| !s.is_empty()).for_each(|s| {
+            let mut tokens = Vec::new();
             let mut phrase = Vec::new();
-            s.split_whitespace().for_each(|word| {
-                if self.stop_words.contains(word.to_lowercase().as_str()) {
+            self.tokenizer.words(s).into_iter().for_each(|word| {
+                if self.is_stopword(word) {
                     if !phrase.is_empty() {
-                        phrases.push(phrase.clone());
-                        phrase.clear();
+                        tokens.push(Token::Phrase(std::mem::take(&mut phrase)));
                     }
+                    tokens.push(Token::Stop(word));
                 } else {
                     phrase.push(word);
                 }
             });
             if !phrase.is_empty() {
-                phrases.push(phrase);
+                tokens.push(Token::Phrase(phrase));
             }
+            stream.push(tokens);
         });
-        phrases
+        stream
+    }
+
+    /// The `phrases` view of a token stream: the `Phrase` tokens in order, with
+    /// every `Stop` discarded.
+    fn stream_phrases<'a>(stream: &[Vec<Token<'a>>]) -> Vec<Vec<&'a str>> {
+        stream
+            .iter()
+            .flatten()
+            .filter_map(|token| match token {
+                Token::Phrase(phrase) => Some(phrase.clone()),
+                Token::Stop(_) => None,
+            })
+            .collect()
     }
 }
 
-impl NumberChecker<&str> for &crate::Rake {
+impl<T: Tokenizer> NumberChecker<&str> for &crate::Rake<T> {
     fn is_number(&self, s: &str) -> bool {
         self.num_re.is_match(s)
     }
 }
+
+/// Union-find root of `i`, with path compression.
+fn find(parent: &mut [usize], i: usize) -> usize {
+    let mut root = i;
+    while parent[root] != root {
+        root = parent[root];
+    }
+    let mut node = i;
+    while parent[node] != root {
+        let next = parent[node];
+        parent[node] = root;
+        node = next;
+    }
+    root
+}
+
+/// Merge the clusters containing `a` and `b`.
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let ra = find(parent, a);
+    let rb = find(parent, b);
+    if ra != rb {
+        parent[ra] = rb;
+    }
+}
+
+/// Whether single-token `a` and `b` share a long common prefix and differ only
+/// by a short inflectional suffix on each side ("parser"/"parsing"). Multi-word
+/// forms never cluster this way, so a keyword is not swallowed by a longer one
+/// it merely prefixes.
+fn shares_prefix(a: &str, b: &str, k: usize) -> bool {
+    if a.contains(' ') || b.contains(' ') {
+        return false;
+    }
+    let common = a.chars().zip(b.chars()).take_while(|(x, y)| x == y).count();
+    if common < 3 {
+        return false;
+    }
+    let max_suffix = k + 2;
+    let rem_a = a.chars().count() - common;
+    let rem_b = b.chars().count() - common;
+    rem_a <= max_suffix && rem_b <= max_suffix
+}
+
+/// Edit budget for fuzzy clustering of `form`: short words tolerate at most one
+/// edit so unrelated three- and four-letter keywords are not swept together.
+fn edit_budget(form: &str, threshold: usize) -> usize {
+    if form.chars().count() <= 4 {
+        threshold.min(1)
+    } else {
+        threshold
+    }
+}
+
+/// Crudely reduces a lowercased word to a base form by stripping a single
+/// possessive or inflectional suffix, so that `is_stopword` can match plural
+/// and possessive variants against a base stopword. Words too short to leave a
+/// meaningful stem are returned unchanged.
+fn stem(word: &str) -> String {
+    for suffix in &["'s", "\u{2019}s", "'", "ing", "ed", "es", "s"] {
+        if word.len() > suffix.len() + 2 && word.ends_with(suffix) {
+            return word[..word.len() - suffix.len()].to_string();
+        }
+    }
+    word.to_string()
+}
+
+/// Configures a [`Rake`] instance. `Rake::new` hard-codes every policy; the
+/// builder exposes the tunable filters that real deployments need — phrase
+/// length limits, a minimum keyword length, a minimum word-occurrence count,
+/// stopword stemming, adjoining-keyword and near-duplicate-merge thresholds —
+/// without forking the crate.
+#[derive(Debug, Clone)]
+pub struct RakeBuilder<T = WhitespaceTokenizer> {
+    stop_words: StopWords,
+    tokenizer: T,
+    min_adjoin: usize,
+    merge_threshold: Option<usize>,
+    min_words: usize,
+    max_words: Option<usize>,
+    min_keyword_len: usize,
+    min_word_count: usize,
+    stem_stopwords: bool,
+}
+
+impl RakeBuilder<WhitespaceTokenizer> {
+    /// Starts a builder with the same defaults as `Rake::new`.
+    pub fn new(stop_words: StopWords) -> Self {
+        RakeBuilder {
+            stop_words: stop_words,
+            tokenizer: WhitespaceTokenizer,
+            min_adjoin: 2,
+            merge_threshold: None,
+            min_words: 1,
+            max_words: None,
+            min_keyword_len: 0,
+            min_word_count: 1,
+            stem_stopwords: false,
+        }
+    }
+}
+
+impl<T: Tokenizer> RakeBuilder<T> {
+    /// Swaps the word-level tokenizer (see [`Tokenizer`]).
+    pub fn tokenizer<U: Tokenizer>(self, tokenizer: U) -> RakeBuilder<U> {
+        RakeBuilder {
+            stop_words: self.stop_words,
+            tokenizer: tokenizer,
+            min_adjoin: self.min_adjoin,
+            merge_threshold: self.merge_threshold,
+            min_words: self.min_words,
+            max_words: self.max_words,
+            min_keyword_len: self.min_keyword_len,
+            min_word_count: self.min_word_count,
+            stem_stopwords: self.stem_stopwords,
+        }
+    }
+
+    /// Minimum number of words in a candidate phrase (default `1`).
+    pub fn min_words(mut self, min_words: usize) -> Self {
+        self.min_words = min_words;
+        self
+    }
+
+    /// Maximum number of words in a candidate phrase; longer phrases are
+    /// dropped. Unbounded by default.
+    pub fn max_words(mut self, max_words: usize) -> Self {
+        self.max_words = Some(max_words);
+        self
+    }
+
+    /// Minimum character length of a candidate keyword (default `0`).
+    pub fn min_keyword_len(mut self, min_keyword_len: usize) -> Self {
+        self.min_keyword_len = min_keyword_len;
+        self
+    }
+
+    /// Drops candidates containing a word that occurs fewer than
+    /// `min_word_count` times in the text (default `1`, i.e. no filtering).
+    pub fn min_word_count(mut self, min_word_count: usize) -> Self {
+        self.min_word_count = min_word_count;
+        self
+    }
+
+    /// Recurrence threshold for adjoining-keyword detection (see
+    /// [`Rake::with_min_adjoin`]).
+    pub fn min_adjoin(mut self, min_adjoin: usize) -> Self {
+        self.min_adjoin = min_adjoin;
+        self
+    }
+
+    /// Edit-distance threshold for near-duplicate merging (see
+    /// [`Rake::with_merge_threshold`]).
+    pub fn merge_threshold(mut self, merge_threshold: usize) -> Self {
+        self.merge_threshold = Some(merge_threshold);
+        self
+    }
+
+    /// Whether to stem plural and possessive forms onto their base stopword
+    /// before the stopword lookup (default `false`).
+    pub fn stem_stopwords(mut self, stem_stopwords: bool) -> Self {
+        self.stem_stopwords = stem_stopwords;
+        self
+    }
+
+    /// Builds the configured `Rake`.
+    pub fn build(self) -> Rake<T> {
+        let mut rake = Rake::with_tokenizer(self.stop_words, self.tokenizer);
+        rake.min_adjoin = self.min_adjoin;
+        rake.merge_threshold = self.merge_threshold;
+        rake.min_words = self.min_words;
+        rake.max_words = self.max_words;
+        rake.min_keyword_len = self.min_keyword_len;
+        rake.min_word_count = self.min_word_count;
+        rake.stem_stopwords = self.stem_stopwords;
+        rake
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stop_words(words: &[&str]) -> StopWords {
+        words.iter().map(|w| w.to_string()).collect()
+    }
+
+    fn has(keywords: &[KeywordScore], keyword: &str) -> bool {
+        keywords.iter().any(|k| k.keyword == keyword)
+    }
+
+    #[test]
+    fn recovers_adjoining_keywords() {
+        let rake = Rake::new(stop_words(&["of"]));
+        let keywords = rake.run("axis of evil. axis of evil.");
+        assert!(has(&keywords, "axis of evil"));
+    }
+
+    #[test]
+    fn adjoining_needs_min_adjoin_occurrences() {
+        let rake = Rake::new(stop_words(&["of", "and", "a"]));
+        // "axis of evil" appears once, below the default threshold of 2.
+        let keywords = rake.run("axis of evil and a lone axis");
+        assert!(!has(&keywords, "axis of evil"));
+    }
+
+    #[test]
+    fn idf_demotes_corpus_wide_words() {
+        let rake = Rake::new(stop_words(&["and"]));
+        let scored = rake.run_corpus(&["apple and banana", "apple and cherry"]);
+        // "apple" is in every document, "banana" only in the first, so the
+        // distinctive word ranks first.
+        assert_eq!(scored[0][0].keyword, "banana");
+    }
+
+    #[test]
+    fn merges_morphological_variants() {
+        let rake = Rake::new(stop_words(&[])).with_merge_threshold(2);
+        let keywords = rake.run("parser, parsing, parsers");
+        // All three variants collapse into one cluster whose score is their sum.
+        assert_eq!(keywords.len(), 1);
+        assert!((keywords[0].score - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn merge_keeps_short_words_apart() {
+        let rake = Rake::new(stop_words(&[])).with_merge_threshold(2);
+        // Edit distance 2 between three-letter words stays above the capped
+        // budget, so "cat" and "cog" are not clustered.
+        let keywords = rake.run("cat, cog");
+        assert_eq!(keywords.len(), 2);
+    }
+
+    #[test]
+    fn merge_does_not_swallow_multi_word_keywords() {
+        let rake = Rake::new(stop_words(&["of"])).with_merge_threshold(2);
+        let keywords = rake.run("axis of evil. axis of evil. axis");
+        assert!(has(&keywords, "axis of evil"));
+        assert!(has(&keywords, "axis"));
+    }
+}
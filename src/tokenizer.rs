@@ -0,0 +1,103 @@
+//! Word-level tokenizers used by `Rake::phrases`. The default splits on
+//! whitespace; enable the `dict-segmenter` feature for the `DictTokenizer`,
+//! which handles scripts without word spaces (Chinese, Japanese, Thai).
+
+/// Turns a single sentence into the words RAKE scores. The returned slices
+/// borrow from `sentence`.
+pub trait Tokenizer {
+    fn words<'a>(&self, sentence: &'a str) -> Vec<&'a str>;
+}
+
+/// The historical behaviour: `str::split_whitespace`.
+#[derive(Debug, Clone, Default)]
+pub struct WhitespaceTokenizer;
+
+impl Tokenizer for WhitespaceTokenizer {
+    fn words<'a>(&self, sentence: &'a str) -> Vec<&'a str> {
+        sentence.split_whitespace().collect()
+    }
+}
+
+/// A dictionary-driven forward maximum-matching segmenter: at each position it
+/// takes the longest word in the dictionary, falling back to a single
+/// character for out-of-vocabulary spans.
+#[cfg(feature = "dict-segmenter")]
+#[derive(Debug, Clone, Default)]
+pub struct DictTokenizer {
+    dict: DictNode,
+    max_len: usize,
+}
+
+#[cfg(feature = "dict-segmenter")]
+#[derive(Debug, Clone, Default)]
+struct DictNode {
+    children: std::collections::HashMap<char, DictNode>,
+    is_word: bool,
+}
+
+#[cfg(feature = "dict-segmenter")]
+impl DictTokenizer {
+    /// Build a segmenter from a dictionary of words (one per entry).
+    pub fn new<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut dict = DictNode::default();
+        let mut max_len = 1;
+        for word in words {
+            let word = word.as_ref();
+            let count = word.chars().count();
+            if count == 0 {
+                continue;
+            }
+            if count > max_len {
+                max_len = count;
+            }
+            let mut node = &mut dict;
+            for ch in word.chars() {
+                node = node.children.entry(ch).or_default();
+            }
+            node.is_word = true;
+        }
+        DictTokenizer { dict, max_len }
+    }
+
+    /// Length, in bytes, of the longest dictionary word that is a prefix of
+    /// `run`, or `None` if no dictionary word is a prefix of `run`.
+    fn longest_match(&self, run: &str) -> Option<usize> {
+        let mut node = &self.dict;
+        let mut matched = None;
+        let mut taken = 0;
+        for (offset, ch) in run.char_indices().take(self.max_len) {
+            node = match node.children.get(&ch) {
+                Some(child) => child,
+                None => break,
+            };
+            taken = offset + ch.len_utf8();
+            if node.is_word {
+                matched = Some(taken);
+            }
+        }
+        matched
+    }
+}
+
+#[cfg(feature = "dict-segmenter")]
+impl Tokenizer for DictTokenizer {
+    fn words<'a>(&self, sentence: &'a str) -> Vec<&'a str> {
+        let mut words = Vec::new();
+        for run in sentence.split_whitespace() {
+            let mut start = 0;
+            while start < run.len() {
+                let rest = &run[start..];
+                let take = self
+                    .longest_match(rest)
+                    .unwrap_or_else(|| rest.chars().next().map_or(0, char::len_utf8));
+                words.push(&rest[..take]);
+                start += take;
+            }
+        }
+        words
+    }
+}
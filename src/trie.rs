@@ -0,0 +1,67 @@
+//! A character trie with bounded fuzzy lookup, used to cluster near-duplicate
+//! keywords in `Rake::run`.
+
+use std::collections::HashMap;
+
+/// A trie over `char`s; each node optionally stores the keyword that ends on
+/// it.
+#[derive(Debug, Clone, Default)]
+pub struct Trie {
+    root: Node,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    children: HashMap<char, Node>,
+    keyword: Option<String>,
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Trie::default()
+    }
+
+    /// Inserts `keyword`, storing it verbatim on its terminal node.
+    pub fn insert(&mut self, keyword: &str) {
+        let mut node = &mut self.root;
+        for ch in keyword.chars() {
+            node = node.children.entry(ch).or_default();
+        }
+        node.keyword = Some(keyword.to_owned());
+    }
+
+    /// Returns every stored keyword within edit distance `k` of `query`.
+    pub fn fuzzy(&self, query: &str, k: usize) -> Vec<String> {
+        let query: Vec<char> = query.chars().collect();
+        let row: Vec<usize> = (0..=query.len()).collect();
+        let mut out = Vec::new();
+        for (ch, child) in &self.root.children {
+            child.search(*ch, &query, &row, k, &mut out);
+        }
+        out
+    }
+}
+
+impl Node {
+    /// Extends the Levenshtein row by the column for `ch`, records the keyword
+    /// if within `k`, and recurses while some cell is still within budget.
+    fn search(&self, ch: char, query: &[char], prev: &[usize], k: usize, out: &mut Vec<String>) {
+        let cols = query.len() + 1;
+        let mut cur = vec![0usize; cols];
+        cur[0] = prev[0] + 1;
+        for i in 1..cols {
+            let cost = if query[i - 1] == ch { 0 } else { 1 };
+            cur[i] = (prev[i - 1] + cost).min(prev[i] + 1).min(cur[i - 1] + 1);
+        }
+        if let Some(keyword) = &self.keyword {
+            if cur[cols - 1] <= k {
+                out.push(keyword.clone());
+            }
+        }
+        if cur.iter().min().copied().unwrap_or(usize::MAX) <= k {
+            for (next, child) in &self.children {
+                child.search(*next, query, &cur, k, out);
+            }
+        }
+    }
+}